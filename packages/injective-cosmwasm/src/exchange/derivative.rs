@@ -112,10 +112,10 @@ impl DerivativeOrder {
         self.margin.is_zero()
     }
     pub fn is_post_only(&self) -> bool {
-        self.order_type == OrderType::BuyPo || self.order_type == OrderType::SellPo
+        self.order_type.is_post_only()
     }
     pub fn is_atomic(&self) -> bool {
-        self.order_type == OrderType::BuyAtomic || self.order_type == OrderType::SellAtomic
+        self.order_type.is_atomic()
     }
     pub fn get_price(&self) -> FPDecimal {
         self.order_info.price
@@ -145,11 +145,11 @@ impl DerivativeOrder {
 
 impl GenericOrder for DerivativeOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        self.order_type.is_buy()
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        self.order_type.is_sell()
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -217,10 +217,10 @@ impl ShortDerivativeOrder {
         self.margin.is_zero()
     }
     pub fn is_post_only(&self) -> bool {
-        self.order_type == OrderType::BuyPo || self.order_type == OrderType::SellPo
+        self.order_type.is_post_only()
     }
     pub fn is_atomic(&self) -> bool {
-        self.order_type == OrderType::BuyAtomic || self.order_type == OrderType::SellAtomic
+        self.order_type.is_atomic()
     }
     pub fn get_price(&self) -> FPDecimal {
         self.order_info.price
@@ -247,7 +247,7 @@ impl ShortDerivativeOrder {
         self.order_type.to_owned()
     }
     pub fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        self.order_type.is_buy()
     }
 }
 
@@ -290,11 +290,11 @@ impl DerivativeLimitOrder {
 
 impl GenericOrder for DerivativeLimitOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        self.order_type.is_buy()
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        self.order_type.is_sell()
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -1,4 +1,4 @@
-use crate::exchange::types::MarketId;
+use crate::exchange::types::{MarketId, MarketStatus};
 use crate::oracle::types::OracleType;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
@@ -63,7 +63,7 @@ pub struct DerivativeMarket {
     #[serde(default)]
     pub isPerpetual: bool,
     #[serde(default)]
-    pub status: i32,
+    pub status: MarketStatus,
     pub min_price_tick_size: FPDecimal,
     pub min_quantity_tick_size: FPDecimal,
 }
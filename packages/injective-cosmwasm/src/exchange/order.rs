@@ -28,6 +28,35 @@ pub enum OrderType {
     SellAtomic = 10,
 }
 
+impl OrderType {
+    /// Limit order type for the given side, `post_only` picks the post-only variant.
+    /// Reduce-only derivative orders have no dedicated type, they are expressed through a zero margin.
+    pub fn from_side(is_buy: bool, post_only: bool) -> OrderType {
+        match (is_buy, post_only) {
+            (true, false) => OrderType::Buy,
+            (true, true) => OrderType::BuyPo,
+            (false, false) => OrderType::Sell,
+            (false, true) => OrderType::SellPo,
+        }
+    }
+
+    pub fn is_buy(&self) -> bool {
+        matches!(self, OrderType::Buy | OrderType::BuyPo | OrderType::BuyAtomic)
+    }
+
+    pub fn is_sell(&self) -> bool {
+        matches!(self, OrderType::Sell | OrderType::SellPo | OrderType::SellAtomic)
+    }
+
+    pub fn is_post_only(&self) -> bool {
+        matches!(self, OrderType::BuyPo | OrderType::SellPo)
+    }
+
+    pub fn is_atomic(&self) -> bool {
+        matches!(self, OrderType::BuyAtomic | OrderType::SellAtomic)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct OrderData {
     pub market_id: MarketId,
@@ -110,4 +139,34 @@ mod tests {
         let types = vec![OrderType::Undefined, OrderType::Buy, OrderType::SellPo, OrderType::SellAtomic];
         assert_eq!(serde_json_wasm::to_string(&types).unwrap(), "[0,1,8,10]");
     }
+
+    #[test]
+    fn order_type_from_side() {
+        assert_eq!(OrderType::from_side(true, false), OrderType::Buy);
+        assert_eq!(OrderType::from_side(true, true), OrderType::BuyPo);
+        assert_eq!(OrderType::from_side(false, false), OrderType::Sell);
+        assert_eq!(OrderType::from_side(false, true), OrderType::SellPo);
+    }
+
+    #[test]
+    fn order_type_side_flags() {
+        // (order type, serialized value, is_buy, is_sell, is_post_only, is_atomic)
+        let cases = [
+            (OrderType::Undefined, 0, false, false, false, false),
+            (OrderType::Buy, 1, true, false, false, false),
+            (OrderType::Sell, 2, false, true, false, false),
+            (OrderType::BuyPo, 7, true, false, true, false),
+            (OrderType::SellPo, 8, false, true, true, false),
+            (OrderType::BuyAtomic, 9, true, false, false, true),
+            (OrderType::SellAtomic, 10, false, true, false, true),
+        ];
+
+        for (order_type, value, is_buy, is_sell, is_post_only, is_atomic) in cases {
+            assert_eq!(serde_json_wasm::to_string(&order_type).unwrap(), value.to_string());
+            assert_eq!(order_type.is_buy(), is_buy);
+            assert_eq!(order_type.is_sell(), is_sell);
+            assert_eq!(order_type.is_post_only(), is_post_only);
+            assert_eq!(order_type.is_atomic(), is_atomic);
+        }
+    }
 }
@@ -33,11 +33,11 @@ impl SpotLimitOrder {
 
 impl GenericOrder for SpotLimitOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        self.order_type.is_buy()
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        self.order_type.is_sell()
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -93,20 +93,20 @@ impl SpotOrder {
         self.get_price() * self.get_quantity()
     }
     pub fn is_post_only(&self) -> bool {
-        self.order_type == OrderType::BuyPo || self.order_type == OrderType::SellPo
+        self.order_type.is_post_only()
     }
     pub fn is_atomic(&self) -> bool {
-        self.order_type == OrderType::BuyAtomic || self.order_type == OrderType::SellAtomic
+        self.order_type.is_atomic()
     }
 }
 
 impl GenericOrder for SpotOrder {
     fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        self.order_type.is_buy()
     }
 
     fn is_sell(&self) -> bool {
-        self.order_type == OrderType::Sell || self.order_type == OrderType::SellPo || self.order_type == OrderType::SellAtomic
+        self.order_type.is_sell()
     }
 
     fn get_order_type(&self) -> &OrderType {
@@ -177,13 +177,13 @@ impl ShortSpotOrder {
         self.get_price() * self.get_quantity()
     }
     pub fn is_post_only(&self) -> bool {
-        self.order_type == OrderType::BuyPo || self.order_type == OrderType::SellPo
+        self.order_type.is_post_only()
     }
     pub fn is_atomic(&self) -> bool {
-        self.order_type == OrderType::BuyAtomic || self.order_type == OrderType::SellAtomic
+        self.order_type.is_atomic()
     }
     pub fn is_buy(&self) -> bool {
-        self.order_type == OrderType::Buy || self.order_type == OrderType::BuyPo || self.order_type == OrderType::BuyAtomic
+        self.order_type.is_buy()
     }
 }
 
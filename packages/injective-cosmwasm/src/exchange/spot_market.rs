@@ -1,4 +1,4 @@
-use crate::exchange::types::MarketId;
+use crate::exchange::types::{MarketId, MarketStatus};
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -14,7 +14,7 @@ pub struct SpotMarket {
     pub relayer_fee_share_rate: FPDecimal,
     pub market_id: MarketId,
     #[serde(default)]
-    pub status: i32,
+    pub status: MarketStatus,
     pub min_price_tick_size: FPDecimal,
     pub min_quantity_tick_size: FPDecimal,
 }
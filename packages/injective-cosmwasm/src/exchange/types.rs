@@ -3,10 +3,31 @@ use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use serde_repr::Serialize_repr;
+use std::convert::TryFrom;
 use std::fmt;
+use std::ops::{Add, Sub};
 
 use crate::InjectiveQuerier;
 
+/// Validation failures for [`MarketId`], [`SubaccountId`] and [`Hash`]. Each variant carries the full message, so
+/// converting into a `StdError::GenericErr` keeps the text the `StdResult` constructors return.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum IdError {
+    #[error("{0}")]
+    InvalidPrefix(String),
+    #[error("{0}")]
+    InvalidLength(String),
+    #[error("{0}")]
+    InvalidHex(String),
+}
+
+impl From<IdError> for StdError {
+    fn from(err: IdError) -> StdError {
+        StdError::generic_err(err.to_string())
+    }
+}
+
 pub const UNSORTED_CANCELLATION_STRATEGY: i32 = 0;
 pub const FROM_WORST_TO_BEST_CANCELLATION_STRATEGY: i32 = 1;
 
@@ -17,6 +38,54 @@ pub struct Deposit {
     pub total_balance: FPDecimal,
 }
 
+impl Deposit {
+    /// Values the total balance in quote terms. Base deposits are multiplied by `price`,
+    /// quote deposits (`is_base == false`) are already in quote units and returned as-is.
+    pub fn value_in_quote(&self, price: FPDecimal, is_base: bool) -> StdResult<FPDecimal> {
+        if !is_base {
+            return Ok(self.total_balance);
+        }
+        if price.is_negative() {
+            return Err(StdError::generic_err(format!(
+                "Invalid price: cannot value deposit at negative price {}",
+                price
+            )));
+        }
+        Ok(self.total_balance * price)
+    }
+}
+
+impl Add for Deposit {
+    type Output = Deposit;
+
+    fn add(self, rhs: Deposit) -> Deposit {
+        Deposit {
+            available_balance: self.available_balance + rhs.available_balance,
+            total_balance: self.total_balance + rhs.total_balance,
+        }
+    }
+}
+
+/// Field-wise subtraction, failing if either balance would become negative
+impl Sub for Deposit {
+    type Output = StdResult<Deposit>;
+
+    fn sub(self, rhs: Deposit) -> StdResult<Deposit> {
+        let available_balance = self.available_balance - rhs.available_balance;
+        let total_balance = self.total_balance - rhs.total_balance;
+        if available_balance.is_negative() || total_balance.is_negative() {
+            return Err(StdError::generic_err(format!(
+                "Underflow: cannot subtract deposit (available {}, total {}) from (available {}, total {})",
+                rhs.available_balance, rhs.total_balance, self.available_balance, self.total_balance
+            )));
+        }
+        Ok(Deposit {
+            available_balance,
+            total_balance,
+        })
+    }
+}
+
 /// Response to query for aggregate volume for a given market
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct DenomDecimals {
@@ -24,7 +93,9 @@ pub struct DenomDecimals {
     pub decimals: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// Single orderbook level. Ordering compares price first and falls back to quantity,
+/// so a `Vec<PriceLevel>` can be sorted or binary-searched by price directly.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
 pub struct PriceLevel {
     pub p: FPDecimal,
     pub q: FPDecimal,
@@ -56,27 +127,99 @@ pub enum MarketType {
     Derivative,
 }
 
+/// On-chain market status, serialized as the chain's integer value. Integers this crate does not know yet deserialize
+/// as `Unspecified`, so a newer chain status does not fail the whole market response.
+#[derive(Serialize_repr, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[repr(i32)]
+pub enum MarketStatus {
+    #[default]
+    Unspecified = 0,
+    Active = 1,
+    Paused = 2,
+    Demolished = 3,
+    Expired = 4,
+}
+
+impl MarketStatus {
+    pub fn is_active(&self) -> bool {
+        *self == MarketStatus::Active
+    }
+}
+
+impl<'de> Deserialize<'de> for MarketStatus {
+    fn deserialize<D>(deserializer: D) -> Result<MarketStatus, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match i32::deserialize(deserializer)? {
+            1 => MarketStatus::Active,
+            2 => MarketStatus::Paused,
+            3 => MarketStatus::Demolished,
+            4 => MarketStatus::Expired,
+            _ => MarketStatus::Unspecified,
+        })
+    }
+}
+
+/// Checks that everything after the `0x` prefix is a hex digit
+fn is_hex_after_prefix(id: &str) -> bool {
+    id.bytes().skip(2).all(|b| b.is_ascii_hexdigit())
+}
+
 #[derive(Serialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, JsonSchema)]
 pub struct MarketId(String);
 
 impl MarketId {
     pub fn new<S>(market_id_s: S) -> StdResult<Self>
+    where
+        S: Into<String>,
+    {
+        Ok(Self::try_new(market_id_s)?)
+    }
+
+    /// Same as [`MarketId::new`], returning an [`IdError`] callers can match on.
+    pub fn try_new<S>(market_id_s: S) -> Result<Self, IdError>
     where
         S: Into<String>,
     {
         let market_id = market_id_s.into();
 
         if !market_id.starts_with("0x") {
-            return Err(StdError::generic_err("Invalid prefix: market_id must start with 0x"));
+            return Err(IdError::InvalidPrefix("Invalid prefix: market_id must start with 0x".to_string()));
         }
 
         if market_id.len() != 66 {
-            return Err(StdError::generic_err("Invalid length: market_id must be exactly 66 characters"));
+            return Err(IdError::InvalidLength(
+                "Invalid length: market_id must be exactly 66 characters".to_string(),
+            ));
+        }
+
+        if !is_hex_after_prefix(&market_id) {
+            return Err(IdError::InvalidHex(
+                "Invalid hex: market_id must only contain hex characters after 0x".to_string(),
+            ));
         }
 
         Ok(Self(market_id.to_lowercase()))
     }
 
+    /// Runs the same prefix, length and hex checks as [`MarketId::new`].
+    pub fn is_valid(market_id: &str) -> bool {
+        Self::try_new(market_id).is_ok()
+    }
+
+    /// Trims whitespace and lowercases a user-supplied market id before running [`MarketId::new`].
+    /// With `add_missing_prefix`, an id without the `0x` prefix gets one instead of being rejected.
+    pub fn normalize(market_id: &str, add_missing_prefix: bool) -> StdResult<Self> {
+        let market_id = market_id.trim().to_lowercase();
+
+        if add_missing_prefix && !market_id.starts_with("0x") {
+            return Self::new(format!("0x{market_id}"));
+        }
+
+        Self::new(market_id)
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
@@ -137,6 +280,14 @@ impl<'de> Deserialize<'de> for MarketId {
             return Err(D::Error::custom(error_message));
         }
 
+        if !is_hex_after_prefix(&market_id) {
+            let error_message = format!(
+                "Invalid hex in deserialization: market_id must only contain hex characters after 0x, received {}",
+                market_id
+            );
+            return Err(D::Error::custom(error_message));
+        }
+
         Ok(MarketId::unchecked(market_id))
     }
 }
@@ -146,22 +297,43 @@ pub struct SubaccountId(String);
 
 impl SubaccountId {
     pub fn new<S>(subaccount_id_s: S) -> std::result::Result<SubaccountId, cosmwasm_std::StdError>
+    where
+        S: Into<String>,
+    {
+        Ok(Self::try_new(subaccount_id_s)?)
+    }
+
+    /// Same as [`SubaccountId::new`], returning an [`IdError`] callers can match on.
+    pub fn try_new<S>(subaccount_id_s: S) -> Result<SubaccountId, IdError>
     where
         S: Into<String>,
     {
         let subaccount_id = subaccount_id_s.into();
 
         if !subaccount_id.starts_with("0x") {
-            return Err(StdError::generic_err("Invalid prefix: subaccount_id must start with 0x"));
+            return Err(IdError::InvalidPrefix("Invalid prefix: subaccount_id must start with 0x".to_string()));
         }
 
         if subaccount_id.len() != 66 {
-            return Err(StdError::generic_err("Invalid length: subaccount_id must be exactly 66 characters"));
+            return Err(IdError::InvalidLength(
+                "Invalid length: subaccount_id must be exactly 66 characters".to_string(),
+            ));
+        }
+
+        if !is_hex_after_prefix(&subaccount_id) {
+            return Err(IdError::InvalidHex(
+                "Invalid hex: subaccount_id must only contain hex characters after 0x".to_string(),
+            ));
         }
 
         Ok(Self(subaccount_id.to_lowercase()))
     }
 
+    /// Runs the same prefix, length and hex checks as [`SubaccountId::new`].
+    pub fn is_valid(subaccount_id: &str) -> bool {
+        Self::try_new(subaccount_id).is_ok()
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
@@ -177,6 +349,19 @@ impl SubaccountId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Returns the nonce encoded in the last 12 bytes (24 hex characters) of the subaccount id.
+    pub fn nonce(&self) -> Result<u128, IdError> {
+        let id = self.as_str();
+        if id.len() < SUBACCOUNT_NONCE_HEX_LENGTH {
+            return Err(IdError::InvalidLength(format!(
+                "Invalid length: cannot read nonce from subaccount_id {id}"
+            )));
+        }
+
+        let hex_nonce = &id[id.len() - SUBACCOUNT_NONCE_HEX_LENGTH..];
+        u128::from_str_radix(hex_nonce, 16).map_err(|_| IdError::InvalidHex(format!("Invalid hex: cannot read nonce from subaccount_id {id}")))
+    }
 }
 
 impl<'de> Deserialize<'de> for SubaccountId {
@@ -202,6 +387,14 @@ impl<'de> Deserialize<'de> for SubaccountId {
             return Err(D::Error::custom(error_message));
         }
 
+        if !is_hex_after_prefix(&subaccount_id) {
+            let error_message = format!(
+                "Invalid hex in deserialization: subaccount_id must only contain hex characters after 0x, received {}",
+                subaccount_id
+            );
+            return Err(D::Error::custom(error_message));
+        }
+
         Ok(SubaccountId::unchecked(subaccount_id))
     }
 }
@@ -210,8 +403,10 @@ impl<'de> Deserialize<'de> for SubaccountId {
 pub struct ShortSubaccountId(String);
 
 const MAX_SHORT_SUBACCOUNT_NONCE: u16 = 999;
+const SUBACCOUNT_NONCE_HEX_LENGTH: usize = 24;
 
 impl ShortSubaccountId {
+    /// Validates the nonce and stores it in canonical decimal form, so `"007"` and `"7"` compare equal.
     pub fn new<S>(id_s: S) -> Result<ShortSubaccountId, StdError>
     where
         S: Into<String>,
@@ -219,7 +414,7 @@ impl ShortSubaccountId {
         let id = id_s.into();
 
         match id.parse::<u16>() {
-            Ok(value) if value <= MAX_SHORT_SUBACCOUNT_NONCE => Ok(Self(id)),
+            Ok(value) if value <= MAX_SHORT_SUBACCOUNT_NONCE => Ok(Self(value.to_string())),
             _ => Err(StdError::generic_err("Invalid value: ShortSubaccountId must be a number between 0-999")),
         }
     }
@@ -249,7 +444,7 @@ impl<'de> Deserialize<'de> for ShortSubaccountId {
         let id = String::deserialize(deserializer)?;
 
         match id.parse::<u16>() {
-            Ok(value) if value <= MAX_SHORT_SUBACCOUNT_NONCE => Ok(ShortSubaccountId::unchecked(id)),
+            Ok(value) if value <= MAX_SHORT_SUBACCOUNT_NONCE => Ok(ShortSubaccountId::unchecked(value.to_string())),
             _ => Err(D::Error::custom(format!(
                 "Invalid value in deserialization: ShortSubaccountId must be a number between 0-999, received {}",
                 id
@@ -258,10 +453,30 @@ impl<'de> Deserialize<'de> for ShortSubaccountId {
     }
 }
 
+/// Fails instead of wrapping when the subaccount nonce is above 999.
+impl TryFrom<&SubaccountId> for ShortSubaccountId {
+    type Error = StdError;
+
+    fn try_from(subaccount_id: &SubaccountId) -> StdResult<ShortSubaccountId> {
+        match subaccount_id.nonce()? {
+            nonce if nonce <= MAX_SHORT_SUBACCOUNT_NONCE as u128 => Ok(Self(nonce.to_string())),
+            nonce => Err(StdError::generic_err(format!(
+                "Invalid value: ShortSubaccountId must be a number between 0-999, subaccount_id has nonce {nonce}"
+            ))),
+        }
+    }
+}
+
+/// Takes the subaccount nonce modulo 1000; use `TryFrom<&SubaccountId>` to reject nonces above 999.
 impl From<SubaccountId> for ShortSubaccountId {
     fn from(subaccount_id: SubaccountId) -> Self {
-        let last_three_chars = &subaccount_id.as_str()[subaccount_id.as_str().len() - 3..];
-        ShortSubaccountId::unchecked(last_three_chars.to_string())
+        match subaccount_id.nonce() {
+            Ok(nonce) => ShortSubaccountId::unchecked((nonce % (MAX_SHORT_SUBACCOUNT_NONCE as u128 + 1)).to_string()),
+            Err(_) => {
+                let id = subaccount_id.as_str();
+                ShortSubaccountId::unchecked(id.get(id.len().saturating_sub(3)..).unwrap_or(id))
+            }
+        }
     }
 }
 
@@ -356,8 +571,16 @@ impl Hash {
     }
 
     pub fn from_hex<T: AsRef<[u8]>>(s: T) -> StdResult<Hash> {
+        Ok(Self::try_from_hex(s)?)
+    }
+
+    /// Same as [`Hash::from_hex`], returning an [`IdError`] callers can match on.
+    pub fn try_from_hex<T: AsRef<[u8]>>(s: T) -> Result<Hash, IdError> {
         let mut bytes = [0u8; 32];
-        hex::decode_to_slice(s, &mut bytes).map_err(|e| StdError::generic_err(e.to_string()))?;
+        hex::decode_to_slice(s, &mut bytes).map_err(|e| match e {
+            hex::FromHexError::InvalidHexCharacter { .. } => IdError::InvalidHex(e.to_string()),
+            hex::FromHexError::OddLength | hex::FromHexError::InvalidStringLength => IdError::InvalidLength(e.to_string()),
+        })?;
         Ok(Hash::new(bytes))
     }
 }
@@ -370,9 +593,57 @@ impl fmt::Display for Hash {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::StdError;
+    use std::convert::TryFrom;
+
+    use cosmwasm_std::{from_slice, to_vec, StdError};
+    use injective_math::FPDecimal;
+
+    use crate::{create_mock_spot_market, Deposit, Hash, IdError, MarketId, MarketStatus, PriceLevel, ShortSubaccountId, SpotMarket, SubaccountId};
+
+    #[test]
+    fn deposit_value_in_quote() {
+        let deposit = Deposit {
+            available_balance: FPDecimal::must_from_str("5"),
+            total_balance: FPDecimal::must_from_str("12.5"),
+        };
 
-    use crate::{MarketId, SubaccountId};
+        let base_value = deposit.value_in_quote(FPDecimal::must_from_str("4"), true).unwrap();
+        assert_eq!(base_value, FPDecimal::must_from_str("50"));
+
+        let quote_value = deposit.value_in_quote(FPDecimal::must_from_str("4"), false).unwrap();
+        assert_eq!(quote_value, FPDecimal::must_from_str("12.5"));
+
+        let err = deposit.value_in_quote(FPDecimal::must_from_str("-4"), true).unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    fn deposit_add_and_sub() {
+        let deposit = |available: &str, total: &str| Deposit {
+            available_balance: FPDecimal::must_from_str(available),
+            total_balance: FPDecimal::must_from_str(total),
+        };
+
+        assert_eq!(deposit("5", "12.5") + deposit("1.5", "2"), deposit("6.5", "14.5"));
+        assert_eq!((deposit("5", "12.5") - deposit("5", "2.5")).unwrap(), deposit("0", "10"));
+    }
+
+    #[test]
+    fn deposit_sub_underflow() {
+        let deposit = Deposit {
+            available_balance: FPDecimal::must_from_str("5"),
+            total_balance: FPDecimal::must_from_str("12.5"),
+        };
+        let larger = Deposit {
+            available_balance: FPDecimal::must_from_str("6"),
+            total_balance: FPDecimal::must_from_str("6"),
+        };
+
+        assert_eq!(
+            (deposit - larger).unwrap_err(),
+            StdError::generic_err("Underflow: cannot subtract deposit (available 6, total 6) from (available 5, total 12.5)")
+        );
+    }
 
     #[test]
     fn unchecked_subaccount_id_to_lowercase() {
@@ -453,6 +724,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn market_id_is_valid() {
+        assert!(MarketId::is_valid("0x01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B"));
+        assert!(MarketId::is_valid("0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b"));
+
+        // wrong prefix
+        assert!(!MarketId::is_valid("0001EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B"));
+        // too short
+        assert!(!MarketId::is_valid("0x01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16"));
+        // too long
+        assert!(!MarketId::is_valid("0x01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B2"));
+        // invalid hex
+        assert!(!MarketId::is_valid(&format!("0x{}", "z".repeat(64))));
+        assert!(!MarketId::is_valid("0x01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16G"));
+        assert!(from_slice::<MarketId>(format!("\"0x{}\"", "z".repeat(64)).as_bytes()).is_err());
+        assert!(!MarketId::is_valid(""));
+    }
+
+    #[test]
+    fn market_id_normalize() {
+        let expected = MarketId::unchecked("0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b");
+
+        // uppercase
+        assert_eq!(
+            MarketId::normalize("0X01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B", false).unwrap(),
+            expected
+        );
+        // whitespace-padded
+        assert_eq!(
+            MarketId::normalize("  0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b\n", false).unwrap(),
+            expected
+        );
+        // missing prefix
+        assert_eq!(
+            MarketId::normalize("01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B", true).unwrap(),
+            expected
+        );
+        assert_eq!(
+            MarketId::normalize("01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B", false).unwrap_err(),
+            StdError::generic_err("Invalid prefix: market_id must start with 0x")
+        );
+        // still validated after normalizing
+        assert!(MarketId::normalize(" 0x01edfab47f ", true).is_err());
+    }
+
+    #[test]
+    fn subaccount_id_is_valid() {
+        assert!(SubaccountId::is_valid(
+            "0xB5e09b93aCEb70C1711aF078922fA256011D7e56000000000000000000000045"
+        ));
+
+        // wrong prefix
+        assert!(!SubaccountId::is_valid(
+            "00B5e09b93aCEb70C1711aF078922fA256011D7e56000000000000000000000045"
+        ));
+        // too short
+        assert!(!SubaccountId::is_valid(
+            "0xB5e09b93aCEb70C1711aF078922fA256011D7e5600000000000000000000004"
+        ));
+        // too long
+        assert!(!SubaccountId::is_valid(
+            "0xB5e09b93aCEb70C1711aF078922fA256011D7e560000000000000000000000451"
+        ));
+        // invalid hex
+        assert!(!SubaccountId::is_valid(&format!("0x{}", "z".repeat(64))));
+        assert!(!SubaccountId::is_valid(
+            "0xB5e09b93aCEb70C1711aF078922fA256011D7e5600000000000000000000004g"
+        ));
+        assert!(!SubaccountId::is_valid(""));
+    }
+
     #[test]
     fn subaccount_id_unchecked_works() {
         let a = SubaccountId::unchecked("123");
@@ -488,4 +830,188 @@ mod tests {
         let subaccount_id = SubaccountId::unchecked("literal-string");
         assert_eq!(format!("{}", subaccount_id), "literal-string");
     }
+
+    #[test]
+    fn price_levels_sort_by_price() {
+        let mut levels = [
+            PriceLevel::new(FPDecimal::must_from_str("12.5"), FPDecimal::must_from_str("1")),
+            PriceLevel::new(FPDecimal::must_from_str("-3"), FPDecimal::must_from_str("4")),
+            PriceLevel::new(FPDecimal::must_from_str("9"), FPDecimal::must_from_str("10")),
+            PriceLevel::new(FPDecimal::must_from_str("0.5"), FPDecimal::must_from_str("2")),
+            PriceLevel::new(FPDecimal::must_from_str("9"), FPDecimal::must_from_str("3")),
+        ];
+        levels.sort();
+
+        let prices: Vec<FPDecimal> = levels.iter().map(|l| l.p).collect();
+        assert_eq!(
+            prices,
+            vec![
+                FPDecimal::must_from_str("-3"),
+                FPDecimal::must_from_str("0.5"),
+                FPDecimal::must_from_str("9"),
+                FPDecimal::must_from_str("9"),
+                FPDecimal::must_from_str("12.5"),
+            ]
+        );
+        // equal prices fall back to quantity
+        assert_eq!(levels[2].q, FPDecimal::must_from_str("3"));
+        assert_eq!(levels[3].q, FPDecimal::must_from_str("10"));
+
+        let target = PriceLevel::new(FPDecimal::must_from_str("12.5"), FPDecimal::must_from_str("1"));
+        assert_eq!(levels.binary_search(&target), Ok(4));
+    }
+
+    #[test]
+    fn subaccount_id_nonce() {
+        let subaccount_id = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e5600000000000000000000004a");
+        assert_eq!(subaccount_id.nonce().unwrap(), 74);
+
+        let subaccount_id = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e56ffffffffffffffffffffffff");
+        assert_eq!(subaccount_id.nonce().unwrap(), (1u128 << 96) - 1);
+
+        assert!(SubaccountId::unchecked("0x45").nonce().is_err());
+        assert!(
+            SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e56zz0000000000000000000045")
+                .nonce()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn short_subaccount_id_from_subaccount_id() {
+        let cases = [
+            // decimal-looking hex suffix is still read as hex
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000000000000", "0"),
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000000000001", "1"),
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000000000045", "69"),
+            // last three chars are not a decimal number
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e5600000000000000000000004a", "74"),
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e560000000000000000000003e7", "999"),
+            // nonces above 999 wrap around
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e560000000000000000000003e8", "0"),
+            ("0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000000000fff", "95"),
+        ];
+
+        for (subaccount_id, expected) in cases {
+            let short_subaccount_id = ShortSubaccountId::from(SubaccountId::unchecked(subaccount_id));
+            assert_eq!(short_subaccount_id.as_str(), expected, "subaccount_id {subaccount_id}");
+            assert!(ShortSubaccountId::new(short_subaccount_id.as_str()).is_ok());
+        }
+    }
+
+    #[test]
+    fn short_subaccount_id_from_unreadable_nonce_does_not_panic() {
+        let subaccount_id = SubaccountId::unchecked(format!("0x{}", "z".repeat(64)));
+        assert_eq!(ShortSubaccountId::from(subaccount_id).as_str(), "zzz");
+        assert_eq!(ShortSubaccountId::from(SubaccountId::unchecked("0x")).as_str(), "0x");
+
+        assert!(SubaccountId::new(format!("0x{}", "z".repeat(64))).is_err());
+        assert!(from_slice::<SubaccountId>(format!("\"0x{}\"", "z".repeat(64)).as_bytes()).is_err());
+    }
+
+    #[test]
+    fn short_subaccount_id_try_from_subaccount_id() {
+        let subaccount_id = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e5600000000000000000000004a");
+        assert_eq!(ShortSubaccountId::try_from(&subaccount_id).unwrap().as_str(), "74");
+
+        let subaccount_id = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e560000000000000000000003e7");
+        assert_eq!(ShortSubaccountId::try_from(&subaccount_id).unwrap().as_str(), "999");
+
+        let subaccount_id = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e560000000000000000000003e8");
+        assert_eq!(
+            ShortSubaccountId::try_from(&subaccount_id).unwrap_err(),
+            StdError::generic_err("Invalid value: ShortSubaccountId must be a number between 0-999, subaccount_id has nonce 1000")
+        );
+
+        let subaccount_id = SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e56ffffffffffffffffffffffff");
+        assert!(ShortSubaccountId::try_from(&subaccount_id).is_err());
+    }
+
+    #[test]
+    fn short_subaccount_id_is_canonical_decimal() {
+        assert_eq!(ShortSubaccountId::new("007").unwrap(), ShortSubaccountId::new("7").unwrap());
+        assert_eq!(ShortSubaccountId::new("007").unwrap().as_str(), "7");
+        assert_eq!(ShortSubaccountId::new("000").unwrap().as_str(), "0");
+
+        let deserialized: ShortSubaccountId = from_slice(br#""042""#).unwrap();
+        assert_eq!(deserialized, ShortSubaccountId::new("42").unwrap());
+    }
+
+    #[test]
+    fn market_status_maps_chain_integers() {
+        let statuses = [
+            (0, MarketStatus::Unspecified),
+            (1, MarketStatus::Active),
+            (2, MarketStatus::Paused),
+            (3, MarketStatus::Demolished),
+            (4, MarketStatus::Expired),
+        ];
+
+        for (value, status) in statuses {
+            let deserialized: MarketStatus = from_slice(value.to_string().as_bytes()).unwrap();
+            assert_eq!(deserialized, status);
+            assert_eq!(to_vec(&status).unwrap(), value.to_string().into_bytes());
+            assert_eq!(status.is_active(), value == 1);
+        }
+
+        assert_eq!(from_slice::<MarketStatus>(b"5").unwrap(), MarketStatus::Unspecified);
+        assert!(from_slice::<MarketStatus>(b"\"Active\"").is_err());
+    }
+
+    #[test]
+    fn market_with_unknown_status_still_deserializes() {
+        let json = String::from_utf8(to_vec(&create_mock_spot_market("inj", 0)).unwrap()).unwrap();
+        let json = json.replace(r#""status":1"#, r#""status":7"#);
+
+        let market: SpotMarket = from_slice(json.as_bytes()).unwrap();
+        assert_eq!(market.status, MarketStatus::Unspecified);
+    }
+
+    #[test]
+    fn id_errors_match_on_variant() {
+        assert!(matches!(
+            MarketId::try_new("01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b00"),
+            Err(IdError::InvalidPrefix(_))
+        ));
+        assert!(matches!(MarketId::try_new("0x01edfab47f"), Err(IdError::InvalidLength(_))));
+        assert!(matches!(SubaccountId::try_new("b5e09b93"), Err(IdError::InvalidPrefix(_))));
+        assert!(matches!(SubaccountId::try_new("0xb5e09b93"), Err(IdError::InvalidLength(_))));
+        assert!(matches!(
+            MarketId::try_new("0xzzf0cd5dc3d18e0407b88a683871399d52483f06c757858a3a9f388877232b11"),
+            Err(IdError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            SubaccountId::try_new("0xzze09b93aceb70c1711af078922fa256011d7e56000000000000000000000045"),
+            Err(IdError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            SubaccountId::unchecked("0xb5e09b93aceb70c1711af078922fa256011d7e56zz0000000000000000000045").nonce(),
+            Err(IdError::InvalidHex(_))
+        ));
+        assert!(matches!(Hash::try_from_hex("f9c0"), Err(IdError::InvalidLength(_))));
+        assert!(matches!(
+            Hash::try_from_hex("zzc0172ba10dfa4d19088d94f5bf61d3b54d5bd7483a322a982e1373ee8ea31b"),
+            Err(IdError::InvalidHex(_))
+        ));
+    }
+
+    #[test]
+    fn id_error_keeps_std_error_message() {
+        let err: StdError = IdError::InvalidPrefix("Invalid prefix: market_id must start with 0x".to_string()).into();
+        assert_eq!(err, StdError::generic_err("Invalid prefix: market_id must start with 0x"));
+        assert_eq!(
+            MarketId::new("0x01edfab47f").unwrap_err(),
+            StdError::generic_err("Invalid length: market_id must be exactly 66 characters")
+        );
+    }
+
+    #[test]
+    fn hash_from_hex_keeps_hex_error_message() {
+        assert_eq!(Hash::from_hex("f9c0").unwrap_err(), StdError::generic_err("Invalid string length"));
+        assert_eq!(Hash::from_hex("f9c").unwrap_err(), StdError::generic_err("Odd number of digits"));
+        assert_eq!(
+            Hash::from_hex("zz".repeat(32)).unwrap_err(),
+            StdError::generic_err("Invalid character 'z' at position 0")
+        );
+    }
 }
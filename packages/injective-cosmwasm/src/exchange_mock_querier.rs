@@ -12,7 +12,7 @@ use injective_math::FPDecimal;
 use crate::exchange::{
     derivative_market::DerivativeMarket,
     response::QueryOrderbookResponse,
-    types::{MarketVolume, PriceLevel, VolumeByType},
+    types::{MarketStatus, MarketVolume, PriceLevel, VolumeByType},
 };
 use crate::oracle::{
     types::{OracleHistoryOptions, OracleType, PriceState, PythPriceState},
@@ -61,7 +61,7 @@ fn default_spot_market_response_handler(market_id: MarketId) -> QuerierResult {
             taker_fee_rate: FPDecimal::from_str("0.001").unwrap(),
             relayer_fee_share_rate: FPDecimal::from_str("0.4").unwrap(),
             market_id,
-            status: 1,
+            status: MarketStatus::Active,
             min_price_tick_size: FPDecimal::from_str("0.01").unwrap(),
             min_quantity_tick_size: FPDecimal::from_str("1000000000000000.0").unwrap(),
         }),
@@ -100,7 +100,7 @@ fn default_derivative_market_response_handler(market_id: MarketId) -> QuerierRes
                 maker_fee_rate: FPDecimal::from_str("0.001").unwrap(),
                 taker_fee_rate: FPDecimal::from_str("0.002").unwrap(),
                 isPerpetual: true,
-                status: 0,
+                status: MarketStatus::Unspecified,
                 min_price_tick_size: FPDecimal::from_str("100000.0").unwrap(),
                 min_quantity_tick_size: FPDecimal::from_str("0.0001").unwrap(),
             }),
@@ -717,9 +717,10 @@ pub mod handlers {
         exchange_mock_querier::TestCoin, Deposit, DerivativeMarket, DerivativeMarketResponse, EffectivePosition, FullDerivativeMarket,
         FullDerivativeMarketPerpetualInfo, HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery, HandlesOracleVolatilityQuery,
         HandlesPriceLevelsQuery, HandlesSmartQuery, HandlesSubaccountAndDenomQuery, HandlesTraderSpotOrdersToCancelUpToAmountQuery, MarketId,
-        MetadataStatistics, OracleVolatilityResponse, OrderSide, Position, PriceLevel, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket,
-        SpotMarketResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountId, SubaccountPositionInMarketResponse,
-        TradeRecord, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, TrimmedDerivativeLimitOrder, TrimmedSpotLimitOrder,
+        MetadataStatistics, OracleVolatilityResponse, OrderSide, PerpetualMarketFunding, PerpetualMarketFundingResponse, PerpetualMarketInfo,
+        PerpetualMarketInfoResponse, Position, PriceLevel, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket, SpotMarketResponse,
+        SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountId, SubaccountPositionInMarketResponse, TradeRecord,
+        TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, TrimmedDerivativeLimitOrder, TrimmedSpotLimitOrder,
     };
     use crate::{
         HandlesBankAllBalancesQuery, HandlesBankBalanceQuery, HandlesTraderDerivativeOrdersToCancelUpToAmountQuery, MarketMidPriceAndTOBResponse,
@@ -928,6 +929,34 @@ pub mod handlers {
         Some(Box::new(Temp { market, info, mark_price }))
     }
 
+    pub fn create_perpetual_market_funding_handler(funding: Option<PerpetualMarketFunding>) -> Option<Box<dyn HandlesMarketIdQuery>> {
+        struct Temp {
+            funding: Option<PerpetualMarketFunding>,
+        }
+        impl HandlesMarketIdQuery for Temp {
+            fn handle(&self, _: MarketId) -> QuerierResult {
+                let response = PerpetualMarketFundingResponse {
+                    state: self.funding.to_owned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { funding }))
+    }
+
+    pub fn create_perpetual_market_info_handler(info: Option<PerpetualMarketInfo>) -> Option<Box<dyn HandlesMarketIdQuery>> {
+        struct Temp {
+            info: Option<PerpetualMarketInfo>,
+        }
+        impl HandlesMarketIdQuery for Temp {
+            fn handle(&self, _: MarketId) -> QuerierResult {
+                let response = PerpetualMarketInfoResponse { info: self.info.to_owned() };
+                SystemResult::Ok(ContractResult::from(to_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { info }))
+    }
+
     pub fn create_trader_spot_orders_handler(orders: Option<Vec<TrimmedSpotLimitOrder>>) -> Option<Box<dyn HandlesMarketAndSubaccountQuery>> {
         struct Temp {
             orders: Option<Vec<TrimmedSpotLimitOrder>>,
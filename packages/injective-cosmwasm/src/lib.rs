@@ -19,8 +19,8 @@ pub use exchange::{
         subaccount_id_to_ethereum_address, subaccount_id_to_injective_address, subaccount_id_to_unchecked_injective_address,
     },
     types::{
-        DenomDecimals, Deposit, Hash, MarketId, MarketType, PriceLevel, ShortSubaccountId, SubaccountId, FROM_WORST_TO_BEST_CANCELLATION_STRATEGY,
-        UNSORTED_CANCELLATION_STRATEGY,
+        DenomDecimals, Deposit, Hash, IdError, MarketId, MarketStatus, MarketType, PriceLevel, ShortSubaccountId, SubaccountId,
+        FROM_WORST_TO_BEST_CANCELLATION_STRATEGY, UNSORTED_CANCELLATION_STRATEGY,
     },
 };
 pub use oracle::{
@@ -40,8 +40,8 @@ pub use msg::{
     cancel_derivative_order_msg, cancel_spot_order_msg, create_activate_contract_msg, create_batch_update_orders_msg, create_burn_tokens_msg,
     create_deactivate_contract_msg, create_deposit_msg, create_derivative_market_order_msg, create_external_transfer_msg,
     create_increase_position_margin_msg, create_liquidate_position_msg, create_mint_tokens_msg, create_new_denom_msg, create_relay_pyth_prices_msg,
-    create_rewards_opt_out_msg, create_set_token_metadata_msg, create_spot_market_order_msg, create_subaccount_transfer_msg,
-    create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
+    create_relay_pyth_prices_msg_if_changed, create_rewards_opt_out_msg, create_set_token_metadata_msg, create_spot_market_order_msg,
+    create_subaccount_transfer_msg, create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
 };
 
 pub use querier::InjectiveQuerier;
@@ -49,8 +49,9 @@ pub use query::{InjectiveQuery, InjectiveQueryWrapper};
 pub use route::InjectiveRoute;
 #[cfg(not(target_arch = "wasm32"))]
 pub use test_helpers::testing_helpers::{
-    create_mock_spot_market, inj_mock_deps, inj_mock_env, test_market_ids, OwnedDepsExt, TEST_MARKET_ID_1, TEST_MARKET_ID_10, TEST_MARKET_ID_2,
-    TEST_MARKET_ID_3, TEST_MARKET_ID_4, TEST_MARKET_ID_5, TEST_MARKET_ID_6, TEST_MARKET_ID_7, TEST_MARKET_ID_8, TEST_MARKET_ID_9,
+    create_mock_derivative_market, create_mock_price_attestation, create_mock_spot_market, inj_mock_deps, inj_mock_env, inj_mock_env_at,
+    test_market_ids, OwnedDepsExt, TEST_MARKET_ID_1, TEST_MARKET_ID_10, TEST_MARKET_ID_2, TEST_MARKET_ID_3, TEST_MARKET_ID_4, TEST_MARKET_ID_5,
+    TEST_MARKET_ID_6, TEST_MARKET_ID_7, TEST_MARKET_ID_8, TEST_MARKET_ID_9,
 };
 
 pub mod authz;
@@ -1,4 +1,5 @@
 use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, CustomMsg, Deps, StdError, StdResult};
+use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -370,6 +371,31 @@ pub fn create_relay_pyth_prices_msg(sender: Addr, price_attestations: Vec<PriceA
     .into()
 }
 
+/// Same as `create_relay_pyth_prices_msg`, but drops attestations whose price moved less than `min_change_bp`
+/// basis points from the last relayed attestation with the same `price_id`. Returns `None` if nothing is left to relay.
+pub fn create_relay_pyth_prices_msg_if_changed(
+    sender: Addr,
+    price_attestations: Vec<PriceAttestation>,
+    last_prices: &[PriceAttestation],
+    min_change_bp: u32,
+) -> Option<CosmosMsg<InjectiveMsgWrapper>> {
+    let changed: Vec<PriceAttestation> = price_attestations
+        .into_iter()
+        .filter(
+            |attestation| match last_prices.iter().find(|last| last.price_id == attestation.price_id) {
+                Some(last) => price_moved_by_bp(attestation, last, min_change_bp),
+                None => true,
+            },
+        )
+        .collect();
+
+    if changed.is_empty() {
+        None
+    } else {
+        Some(create_relay_pyth_prices_msg(sender, changed))
+    }
+}
+
 pub fn create_mint_tokens_msg(sender: Addr, amount: Coin, mint_to: String) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Tokenfactory,
@@ -446,3 +472,131 @@ pub fn create_deactivate_contract_msg(sender: Addr, contract_address: Addr) -> C
     }
     .into()
 }
+
+/// Whether `attestation` moved at least `min_change_bp` basis points from `last`. Prices sharing an expo are compared
+/// as raw integers, otherwise the scaled prices are compared. A zero or unscalable last price always counts as moved.
+fn price_moved_by_bp(attestation: &PriceAttestation, last: &PriceAttestation, min_change_bp: u32) -> bool {
+    if attestation.expo == last.expo {
+        let (price, last_price) = (attestation.price as i128, last.price as i128);
+        return last_price == 0 || (price - last_price).abs() * 10_000 >= last_price.abs() * min_change_bp as i128;
+    }
+
+    match (attestation.signed_price(), last.signed_price()) {
+        (Ok(price), Ok(last_price)) if !last_price.is_zero() => {
+            (price - last_price).abs() / last_price.abs() >= FPDecimal::from(min_change_bp as u128) / FPDecimal::from(10_000u128)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{to_vec, Addr, CosmosMsg};
+    use injective_math::FPDecimal;
+
+    use crate::{
+        create_batch_update_orders_msg, create_mock_price_attestation, create_relay_pyth_prices_msg_if_changed, InjectiveMsg, InjectiveMsgWrapper,
+        MarketId, OrderData, OrderType, PriceAttestation, SpotOrder, SubaccountId, TEST_MARKET_ID_1, TEST_MARKET_ID_2,
+    };
+
+    fn attestation(price_id: &str, price: i64) -> PriceAttestation {
+        create_mock_price_attestation(price_id, price, price, -3)
+    }
+
+    fn relayed_attestations(msg: CosmosMsg<InjectiveMsgWrapper>) -> Vec<PriceAttestation> {
+        match msg {
+            CosmosMsg::Custom(wrapper) => match wrapper.msg_data {
+                InjectiveMsg::RelayPythPrices { price_attestations, .. } => price_attestations,
+                _ => panic!("expected a relay pyth prices message"),
+            },
+            _ => panic!("expected a custom message"),
+        }
+    }
+
+    #[test]
+    fn batch_update_orders_msg_matches_chain_json() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let subaccount_id = SubaccountId::unchecked("0xc7fc5d3361d21c52c00018c47e8bc53646a0e101000000000000000000000001");
+        let spot_order = SpotOrder::new(
+            FPDecimal::must_from_str("1.5"),
+            FPDecimal::must_from_str("10"),
+            OrderType::BuyPo,
+            &MarketId::unchecked(TEST_MARKET_ID_1),
+            subaccount_id.clone(),
+            Some(sender.clone()),
+        );
+        let derivative_order_to_cancel = OrderData {
+            market_id: MarketId::unchecked(TEST_MARKET_ID_2),
+            subaccount_id: subaccount_id.clone(),
+            order_hash: "0xabc".to_string(),
+        };
+
+        let msg = create_batch_update_orders_msg(
+            sender,
+            Some(subaccount_id),
+            vec![],
+            vec![],
+            vec![],
+            vec![derivative_order_to_cancel],
+            vec![spot_order],
+            vec![],
+        );
+        let wrapper = match msg {
+            CosmosMsg::Custom(wrapper) => wrapper,
+            _ => panic!("expected a custom message"),
+        };
+
+        let expected = format!(
+            concat!(
+                r#"{{"route":"exchange","msg_data":{{"batch_update_orders":{{"#,
+                r#""sender":"inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r","#,
+                r#""subaccount_id":"0xc7fc5d3361d21c52c00018c47e8bc53646a0e101000000000000000000000001","#,
+                r#""spot_market_ids_to_cancel_all":[],"derivative_market_ids_to_cancel_all":[],"spot_orders_to_cancel":[],"#,
+                r#""derivative_orders_to_cancel":[{{"market_id":"{market_2}","subaccount_id":"1","order_hash":"0xabc"}}],"#,
+                r#""spot_orders_to_create":[{{"market_id":"{market_1}","order_info":{{"subaccount_id":"1","#,
+                r#""fee_recipient":"inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r","price":"1.5","quantity":"10"}},"#,
+                r#""order_type":7,"trigger_price":null}}],"derivative_orders_to_create":[]}}}}}}"#
+            ),
+            market_1 = TEST_MARKET_ID_1,
+            market_2 = TEST_MARKET_ID_2,
+        );
+        assert_eq!(String::from_utf8(to_vec(&wrapper).unwrap()).unwrap(), expected);
+    }
+
+    #[test]
+    fn relay_pyth_prices_if_changed_skips_sub_threshold_moves() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let last_prices = vec![attestation("0x01", 100_000)];
+
+        // 0.5% move against a 1% (100 bp) threshold
+        let msg = create_relay_pyth_prices_msg_if_changed(sender, vec![attestation("0x01", 100_500)], &last_prices, 100);
+        assert!(msg.is_none());
+    }
+
+    #[test]
+    fn relay_pyth_prices_if_changed_keeps_super_threshold_and_new_prices() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let last_prices = vec![attestation("0x01", 100_000), attestation("0x02", 100_000)];
+        let attestations = vec![attestation("0x01", 98_000), attestation("0x02", 100_100), attestation("0x03", 5_000)];
+
+        let msg = create_relay_pyth_prices_msg_if_changed(sender, attestations, &last_prices, 100).unwrap();
+        assert_eq!(relayed_attestations(msg), vec![attestation("0x01", 98_000), attestation("0x03", 5_000)]);
+    }
+
+    #[test]
+    fn relay_pyth_prices_if_changed_handles_last_prices_that_scale_to_zero() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let last_prices = vec![
+            create_mock_price_attestation("0x01", 5, 5, -20),
+            create_mock_price_attestation("0x02", 5, 5, -20),
+        ];
+        // same expo compares raw prices, a different expo falls back to relaying against a zero last price
+        let attestations = vec![
+            create_mock_price_attestation("0x01", 5, 5, -20),
+            create_mock_price_attestation("0x02", 5, 5, -19),
+        ];
+
+        let msg = create_relay_pyth_prices_msg_if_changed(sender, attestations, &last_prices, 100).unwrap();
+        assert_eq!(relayed_attestations(msg), vec![create_mock_price_attestation("0x02", 5, 5, -19)]);
+    }
+}
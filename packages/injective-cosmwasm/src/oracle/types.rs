@@ -1,4 +1,5 @@
 use crate::oracle::volatility::{MetadataStatistics, TradeRecord};
+use cosmwasm_std::{StdError, StdResult};
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -65,6 +66,33 @@ pub struct PriceAttestation {
     pub publish_time: i64,
 }
 
+impl PriceAttestation {
+    /// Price scaled by `expo`, keeping its sign, e.g. `price: -12345, expo: -2` gives `-123.45`.
+    /// Fails if `|expo|` is above 38, where `10^|expo|` no longer fits in a `u128`.
+    pub fn signed_price(&self) -> StdResult<FPDecimal> {
+        scale_by_expo(self.price, self.expo)
+    }
+
+    /// EMA price scaled by `expo`, keeping its sign. Fails under the same conditions as [`PriceAttestation::signed_price`].
+    pub fn signed_ema_price(&self) -> StdResult<FPDecimal> {
+        scale_by_expo(self.ema_price, self.expo)
+    }
+}
+
+fn scale_by_expo(value: i64, expo: i32) -> StdResult<FPDecimal> {
+    let scale = 10u128
+        .checked_pow(expo.unsigned_abs())
+        .ok_or_else(|| StdError::generic_err(format!("Invalid expo: cannot scale price by 10^{expo}")))?;
+
+    let value = FPDecimal::from(value as i128);
+    let scale = FPDecimal::from(scale);
+    if expo < 0 {
+        Ok(value / scale)
+    } else {
+        Ok(value * scale)
+    }
+}
+
 #[derive(Serialize_repr, Deserialize_repr, Clone, Debug, PartialEq, Eq, JsonSchema, Copy)]
 #[repr(i32)]
 pub enum PythStatus {
@@ -116,3 +144,54 @@ pub struct PythPriceState {
     pub publish_time: i64,
     pub price_state: PriceState,
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_slice, to_vec, StdError};
+    use injective_math::FPDecimal;
+
+    use crate::create_mock_price_attestation;
+    use crate::oracle::types::PriceAttestation;
+
+    fn attestation(price: i64, ema_price: i64, expo: i32) -> PriceAttestation {
+        create_mock_price_attestation(
+            "0xf9c0172ba10dfa4d19088d94f5bf61d3b54d5bd7483a322a982e1373ee8ea31b",
+            price,
+            ema_price,
+            expo,
+        )
+    }
+
+    #[test]
+    fn signed_price_keeps_negative_sign() {
+        let attestation = attestation(-12345, -12000, -2);
+
+        assert_eq!(attestation.signed_price().unwrap(), FPDecimal::must_from_str("-123.45"));
+        assert_eq!(attestation.signed_ema_price().unwrap(), FPDecimal::must_from_str("-120"));
+    }
+
+    #[test]
+    fn signed_price_applies_positive_expo() {
+        assert_eq!(attestation(42, 40, 3).signed_price().unwrap(), FPDecimal::must_from_str("42000"));
+    }
+
+    #[test]
+    fn signed_price_rejects_out_of_range_expo() {
+        assert!(attestation(1, 1, -38).signed_price().unwrap().is_zero());
+        assert_eq!(
+            attestation(5, 5, -40).signed_price().unwrap_err(),
+            StdError::generic_err("Invalid expo: cannot scale price by 10^-40")
+        );
+        assert!(attestation(5, 5, 39).signed_ema_price().is_err());
+        assert!(attestation(5, 5, i32::MIN).signed_price().is_err());
+    }
+
+    #[test]
+    fn negative_price_round_trips_through_json() {
+        let attestation = attestation(-12345, -12000, -2);
+
+        let decoded: PriceAttestation = from_slice(&to_vec(&attestation).unwrap()).unwrap();
+        assert_eq!(decoded, attestation);
+        assert_eq!(decoded.signed_price().unwrap(), FPDecimal::must_from_str("-123.45"));
+    }
+}
@@ -511,3 +511,133 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use crate::{
+        create_derivative_market_handler, create_market_mid_price_and_tob_handler, create_mock_derivative_market,
+        create_perpetual_market_funding_handler, create_perpetual_market_info_handler, inj_mock_deps, FullDerivativeMarketPerpetualInfo,
+        InjectiveQuerier, MarketId, PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState, TEST_MARKET_ID_1, TEST_MARKET_ID_2,
+    };
+
+    #[test]
+    fn query_perpetual_market_funding_returns_seeded_state() {
+        let funding = PerpetualMarketFunding {
+            cumulative_funding: FPDecimal::must_from_str("-0.0125"),
+            cumulative_price: FPDecimal::must_from_str("3.5"),
+            last_timestamp: 1_675_000_000,
+        };
+        let seeded = funding.clone();
+        let deps = inj_mock_deps(|querier| querier.perpetual_market_funding_response_handler = create_perpetual_market_funding_handler(Some(seeded)));
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let market_id = MarketId::unchecked(TEST_MARKET_ID_1);
+        let response = querier.query_perpetual_market_funding(&market_id).unwrap();
+        assert_eq!(response.state, Some(funding));
+    }
+
+    #[test]
+    fn query_perpetual_market_funding_defaults_to_empty_state() {
+        let deps = inj_mock_deps(|_| {});
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let market_id = MarketId::unchecked(TEST_MARKET_ID_1);
+        let response = querier.query_perpetual_market_funding(&market_id).unwrap();
+        assert_eq!(response.state, None);
+    }
+
+    #[test]
+    fn query_perpetual_market_info_returns_seeded_info() {
+        let market_id = MarketId::unchecked(TEST_MARKET_ID_1);
+        let info = PerpetualMarketInfo {
+            market_id: market_id.clone(),
+            hourly_funding_rate_cap: FPDecimal::must_from_str("0.000625"),
+            hourly_interest_rate: FPDecimal::must_from_str("0.00000416666"),
+            next_funding_timestamp: 1_675_003_600,
+            funding_interval: 3600,
+        };
+        let seeded = info.clone();
+        let deps = inj_mock_deps(|querier| querier.perpetual_market_info_response_handler = create_perpetual_market_info_handler(Some(seeded)));
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let response = querier.query_perpetual_market_info(&market_id).unwrap();
+        assert_eq!(response.info, Some(info));
+    }
+
+    #[test]
+    fn query_derivative_market_mid_price_and_tob_returns_seeded_prices() {
+        let market_id = MarketId::unchecked(TEST_MARKET_ID_1);
+        let deps = inj_mock_deps(|querier| {
+            querier.derivative_market_mid_price_and_tob_response_handler = create_market_mid_price_and_tob_handler(
+                Some(FPDecimal::must_from_str("20.5")),
+                Some(FPDecimal::must_from_str("20")),
+                Some(FPDecimal::must_from_str("21")),
+            )
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let response = querier.query_derivative_market_mid_price_and_tob(&market_id).unwrap();
+        assert_eq!(response.mid_price, Some(FPDecimal::must_from_str("20.5")));
+        assert_eq!(response.best_buy_price, Some(FPDecimal::must_from_str("20")));
+        assert_eq!(response.best_sell_price, Some(FPDecimal::must_from_str("21")));
+    }
+
+    #[test]
+    fn query_derivative_market_returns_market_info_and_funding() {
+        let market = create_mock_derivative_market("inj", 0);
+        let market_id = market.market_id.clone();
+        let perpetual_info = FullDerivativeMarketPerpetualInfo {
+            perpetual_info: PerpetualMarketState {
+                market_info: PerpetualMarketInfo {
+                    market_id: market_id.clone(),
+                    hourly_funding_rate_cap: FPDecimal::must_from_str("0.000625"),
+                    hourly_interest_rate: FPDecimal::must_from_str("0.00000416666"),
+                    next_funding_timestamp: 1_675_003_600,
+                    funding_interval: 3600,
+                },
+                funding_info: PerpetualMarketFunding {
+                    cumulative_funding: FPDecimal::must_from_str("0.25"),
+                    cumulative_price: FPDecimal::must_from_str("1.5"),
+                    last_timestamp: 1_675_000_000,
+                },
+            },
+        };
+        let (seeded_market, seeded_info) = (market.clone(), perpetual_info.clone());
+        let deps = inj_mock_deps(|querier| {
+            querier.derivative_market_response_handler =
+                create_derivative_market_handler(Some(seeded_market), Some(seeded_info), FPDecimal::must_from_str("12.5"))
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let full_market = querier.query_derivative_market(&market_id).unwrap().market;
+        assert_eq!(full_market.market, Some(market));
+        assert_eq!(full_market.info, Some(perpetual_info));
+        assert_eq!(full_market.mark_price, FPDecimal::must_from_str("12.5"));
+    }
+
+    #[test]
+    fn query_spot_market_mid_price_and_tob_returns_seeded_prices() {
+        let market_id = MarketId::unchecked(TEST_MARKET_ID_2);
+        let deps = inj_mock_deps(|querier| {
+            querier.spot_market_mid_price_and_tob_response_handler = create_market_mid_price_and_tob_handler(
+                Some(FPDecimal::must_from_str("1.005")),
+                Some(FPDecimal::must_from_str("1")),
+                Some(FPDecimal::must_from_str("1.01")),
+            )
+        });
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let response = querier.query_spot_market_mid_price_and_tob(&market_id).unwrap();
+        assert_eq!(response.mid_price, Some(FPDecimal::must_from_str("1.005")));
+        assert_eq!(response.best_buy_price, Some(FPDecimal::must_from_str("1")));
+        assert_eq!(response.best_sell_price, Some(FPDecimal::must_from_str("1.01")));
+    }
+}
@@ -13,8 +13,12 @@ pub(crate) mod testing_helpers {
     use injective_math::FPDecimal;
 
     use crate::{
-        exchange::{spot_market::SpotMarket, types::MarketId},
-        InjectiveQueryWrapper, WasmMockQuerier,
+        exchange::{
+            derivative_market::DerivativeMarket,
+            spot_market::SpotMarket,
+            types::{MarketId, MarketStatus},
+        },
+        InjectiveQueryWrapper, OracleType, PriceAttestation, PythStatus, WasmMockQuerier,
     };
 
     pub const TEST_CONTRACT_ADDR: &str = "inj14hj2tavq8fpesdwxxcu44rty3hh90vhujaxlnz";
@@ -46,10 +50,15 @@ pub(crate) mod testing_helpers {
     }
 
     pub fn inj_mock_env() -> Env {
+        inj_mock_env_at(Timestamp::from_nanos(1_571_797_419_879_305_533))
+    }
+
+    /// Same as `inj_mock_env`, with the block time set to `time` for time-dependent tests
+    pub fn inj_mock_env_at(time: Timestamp) -> Env {
         Env {
             block: BlockInfo {
                 height: 12_345,
-                time: Timestamp::from_nanos(1_571_797_419_879_305_533),
+                time,
                 chain_id: "cosmos-testnet-14002".to_string(),
             },
             transaction: Some(TransactionInfo { index: 3 }),
@@ -105,9 +114,66 @@ pub(crate) mod testing_helpers {
             taker_fee_rate: FPDecimal::from_str("0.002").unwrap(),
             relayer_fee_share_rate: FPDecimal::from_str("0.4").unwrap(),
             market_id: test_market_ids()[idx as usize].clone(),
-            status: 1,
+            status: MarketStatus::Active,
             min_price_tick_size: FPDecimal::from_str("0.01").unwrap(),
             min_quantity_tick_size: FPDecimal::from_str("0.01").unwrap(),
         }
     }
+
+    pub fn create_mock_derivative_market(base: &str, idx: u32) -> DerivativeMarket {
+        DerivativeMarket {
+            ticker: format!("{base}usdt"),
+            oracle_base: base.to_string(),
+            oracle_quote: "usdt".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            oracle_scale_factor: 0,
+            quote_denom: "usdt".to_string(),
+            market_id: test_market_ids()[idx as usize].clone(),
+            initial_margin_ratio: FPDecimal::from_str("0.05").unwrap(),
+            maintenance_margin_ratio: FPDecimal::from_str("0.02").unwrap(),
+            maker_fee_rate: FPDecimal::from_str("0.001").unwrap(),
+            taker_fee_rate: FPDecimal::from_str("0.002").unwrap(),
+            isPerpetual: true,
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::from_str("0.01").unwrap(),
+            min_quantity_tick_size: FPDecimal::from_str("0.01").unwrap(),
+        }
+    }
+
+    pub fn create_mock_price_attestation(price_id: &str, price: i64, ema_price: i64, expo: i32) -> PriceAttestation {
+        PriceAttestation {
+            product_id: "product".to_string(),
+            price_id: price_id.to_string(),
+            price,
+            conf: 500,
+            expo,
+            ema_price,
+            ema_conf: 600,
+            status: PythStatus::Trading,
+            num_publishers: 10,
+            max_num_publishers: 20,
+            attestation_time: 1_675_000_000,
+            publish_time: 1_675_000_000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Timestamp;
+
+    use crate::{inj_mock_env, inj_mock_env_at};
+
+    #[test]
+    fn inj_mock_env_at_only_moves_block_time() {
+        let env = inj_mock_env();
+        let later = env.block.time.plus_seconds(90);
+
+        let moved = inj_mock_env_at(later);
+        assert_eq!(moved.block.time, later);
+        assert_eq!(moved.block.time.seconds() - env.block.time.seconds(), 90);
+        assert_eq!(moved.block.height, env.block.height);
+        assert_eq!(moved.contract, env.contract);
+        assert_eq!(inj_mock_env_at(Timestamp::from_seconds(0)).block.time, Timestamp::from_seconds(0));
+    }
 }
@@ -433,6 +433,14 @@ mod tests {
             sign: 1,
         };
         assert_eq!(neg_five.abs(), five);
+        assert_eq!(five.abs(), five);
+        assert_eq!(FPDecimal::zero().abs(), FPDecimal::zero());
+
+        let neg_zero = FPDecimal {
+            num: U256([0, 0, 0, 0]),
+            sign: 0,
+        };
+        assert_eq!(neg_zero.abs(), FPDecimal::zero());
     }
 
     #[test]
@@ -553,7 +561,7 @@ mod tests {
         // even a manually assigned negative zero value returns positive
         let val = FPDecimal {
             num: U256([0, 0, 0, 0]),
-            sign: 1,
+            sign: 0,
         };
         assert!(!val.is_negative());
 
@@ -18,6 +18,22 @@ pub fn parse_dec(vs: &str, min: Option<&FPDecimal>, max: Option<&FPDecimal>, ran
     Ok(v)
 }
 
+/// Parses a decimal string, dropping fractional digits past the 18 supported by [`FPDecimal`]
+/// instead of failing. The dropped digits are truncated, i.e. the value is rounded toward zero.
+pub fn parse_dec_truncated(vs: &str) -> Result<FPDecimal, StdError> {
+    let mut v = match vs.split_once('.') {
+        Some((integer, fraction)) if fraction.len() > FPDecimal::DIGITS && fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            FPDecimal::from_str(&format!("{integer}.{}", &fraction[..FPDecimal::DIGITS]))?
+        }
+        _ => FPDecimal::from_str(vs)?,
+    };
+    // a negative input truncated to zero would otherwise keep its sign and compare unequal to zero
+    if v.is_zero() {
+        v.sign = 1;
+    }
+    Ok(v)
+}
+
 pub fn parse_int<T: FromStr + Ord + Display>(vs: &str, min: Option<&T>, max: Option<&T>, range_ends: RangeEnds) -> Result<T, StdError>
 where
     <T as FromStr>::Err: ToString,
@@ -97,6 +113,32 @@ pub fn round_to_nearest_tick(num: FPDecimal, min_tick: FPDecimal) -> FPDecimal {
     }
 }
 
+/// Renders `value` with exactly `decimals` fractional digits, rounding half away from zero.
+/// Digits beyond the 18 supported by [`FPDecimal`] are zero-padded.
+pub fn fp_to_fixed_string(value: &FPDecimal, decimals: u32) -> String {
+    let kept_digits = decimals.min(FPDecimal::DIGITS as u32);
+
+    let dropped_scale = U256::exp10((FPDecimal::DIGITS as u32 - kept_digits) as usize);
+    let mut scaled = value.num / dropped_scale;
+    if (value.num % dropped_scale) * U256::from(2u64) >= dropped_scale && dropped_scale > U256::one() {
+        scaled = scaled + U256::one();
+    }
+
+    let kept_scale = U256::exp10(kept_digits as usize);
+    let sign = if value.is_negative() && !scaled.is_zero() { "-" } else { "" };
+    let integer = scaled / kept_scale;
+    if decimals == 0 {
+        return format!("{sign}{integer}");
+    }
+
+    let fraction = (scaled % kept_scale).to_string();
+    format!(
+        "{sign}{integer}.{}{fraction}{}",
+        "0".repeat(kept_digits as usize - fraction.len()),
+        "0".repeat((decimals - kept_digits) as usize)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +211,47 @@ mod tests {
             assert_eq!(expected, output);
         }
     }
+
+    #[test]
+    fn test_fp_to_fixed_string() {
+        // input, decimals, expected
+        let data = [
+            ("1.2345", 2, "1.23"),
+            ("1.235", 2, "1.24"),
+            ("-1.235", 2, "-1.24"),
+            ("1.5", 0, "2"),
+            ("7", 3, "7.000"),
+            ("0.05", 4, "0.0500"),
+            ("0", 2, "0.00"),
+            ("-0.004", 2, "0.00"),
+            ("0.999", 2, "1.00"),
+            ("0.000000000000000001", 20, "0.00000000000000000100"),
+        ];
+
+        for (input, decimals, expected) in data {
+            assert_eq!(fp_to_fixed_string(&FPDecimal::must_from_str(input), decimals), expected, "input {input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_dec_truncated() {
+        // input, expected
+        let data = [
+            ("0.12345678901234567891", "0.123456789012345678"),
+            ("-1.99999999999999999999", "-1.999999999999999999"),
+            ("2.5", "2.5"),
+            ("7", "7"),
+            ("0.000000000000000000999", "0"),
+            ("-0.0000000000000000009", "0"),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(parse_dec_truncated(input).unwrap(), FPDecimal::must_from_str(expected), "input {input}");
+        }
+
+        assert_eq!(parse_dec_truncated("-0.0000000000000000009").unwrap(), FPDecimal::ZERO);
+        assert!(FPDecimal::from_str("0.12345678901234567891").is_err());
+        assert!(parse_dec_truncated("1.2345678901234567890x").is_err());
+        assert!(parse_dec_truncated("abc").is_err());
+    }
 }